@@ -1,13 +1,18 @@
 // WebAssembly Game Engine for ThreadPulse Daily 2026
 // Optimized for near-native performance in browsers
 
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::sync::{Arc, Mutex};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsValue;
 use serde::{Deserialize, Serialize};
 use js_sys::Promise;
 use web_sys::console;
+#[cfg(not(target_arch = "wasm32"))]
+use rayon::prelude::*;
 
 // Advanced WebAssembly game engine for ThreadPulse Daily 2026
 // Optimized for performance with parallel processing and memory management
@@ -42,6 +47,13 @@ pub struct PlayerProgress {
     pub accuracy: f64,
     pub avg_solve_time: u64,
     pub skill_level: f64,
+    // `#[serde(default)]` so save states persisted before the Elo and
+    // soft-pity subsystems existed still deserialize, defaulting new players
+    // to zero games played / zero pity.
+    #[serde(default)]
+    pub games_played: u32,
+    #[serde(default)]
+    pub reward_pity: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,6 +63,24 @@ pub struct Guess {
     pub hints_used: u8,
     pub correct: bool,
     pub score: Option<u32>,
+    // Milliseconds spent on this guess specifically (since the previous
+    // guess, or since the puzzle started for the first one), not the
+    // timestamp itself — `MetricsWindow` sums this to get windowed solve
+    // time. `#[serde(default)]` so guess history saved before this field
+    // existed still deserializes, at the cost of reporting 0ms for those
+    // older guesses.
+    #[serde(default)]
+    pub duration_ms: u64,
+}
+
+// Per-letter feedback for a single guess, with discriminants `Absent = 0`,
+// `Present = 1`, `Correct = 2` so the discriminant doubles as the base-3 digit
+// in `encode_feedback`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LetterFeedback {
+    Absent = 0,
+    Present = 1,
+    Correct = 2,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -73,8 +103,17 @@ pub struct ThreadPulseEngine {
     performance_metrics: Arc<Mutex<PerformanceMetrics>>,
     ai_models: Arc<Mutex<AIModels>>,
     cache: Arc<Mutex<HashMap<String, CachedData>>>,
+    score_config: Arc<Mutex<ScoreConfig>>,
+    reward_curve: Arc<Mutex<Vec<f64>>>,
+    // Elo-scale puzzle ratings, keyed by puzzle id, kept separate from
+    // `Puzzle.difficulty` so self-calibration never poisons the scoring
+    // input. Seeded from `difficulty_to_rating` the first time a puzzle is
+    // rated.
+    puzzle_ratings: Arc<Mutex<HashMap<String, f64>>>,
 }
 
+const MAX_REWARD_PITY: u32 = 100;
+
 #[derive(Debug, Default)]
 pub struct PerformanceMetrics {
     pub total_operations: u64,
@@ -99,6 +138,82 @@ pub struct Model {
     pub bias: Vec<f32>,
     pub layers: Vec<usize>,
     pub compiled: bool,
+    // Ping-pong activation buffers, sized to the widest layer and reused
+    // across calls so `infer`'s layer-by-layer pass allocates nothing after
+    // the first call; only the returned output vector is allocated per call.
+    scratch: RefCell<[Vec<f32>; 2]>,
+}
+
+impl Model {
+    // `layers` implies exact `weights`/`bias` lengths (sum of `in * out` per
+    // transition, and sum of `out` respectively) and a fixed input width
+    // (`layers[0]`). A malformed or stale compiled model — e.g. one whose
+    // input width doesn't match `input`, or whose weight/bias buffers don't
+    // match `layers` — must be rejected here rather than let `infer` index
+    // out of bounds.
+    pub fn is_valid_for(&self, input_len: usize) -> bool {
+        if self.layers.len() < 2 || self.layers[0] != input_len {
+            return false;
+        }
+
+        let mut expected_weights = 0usize;
+        let mut expected_bias = 0usize;
+        for layer in 1..self.layers.len() {
+            expected_weights += self.layers[layer - 1] * self.layers[layer];
+            expected_bias += self.layers[layer];
+        }
+
+        self.weights.len() == expected_weights && self.bias.len() == expected_bias
+    }
+
+    // Feed-forward pass: walks `layers` as layer sizes, applying
+    // `weight·x + bias` with a ReLU between hidden layers and a sigmoid on
+    // the output layer. Callers must check `is_valid_for(input.len())`
+    // first — this does no bounds validation of its own.
+    pub fn infer(&self, input: &[f32]) -> Vec<f32> {
+        if self.layers.len() < 2 {
+            return Vec::new();
+        }
+
+        let max_width = *self.layers.iter().max().unwrap();
+        let mut scratch = self.scratch.borrow_mut();
+        for buf in scratch.iter_mut() {
+            if buf.len() < max_width {
+                buf.resize(max_width, 0.0);
+            }
+        }
+
+        scratch[0][..input.len()].copy_from_slice(input);
+
+        let mut weight_offset = 0;
+        let mut bias_offset = 0;
+        let mut current = 0;
+
+        for layer in 1..self.layers.len() {
+            let in_size = self.layers[layer - 1];
+            let out_size = self.layers[layer];
+            let is_output = layer == self.layers.len() - 1;
+            let next = 1 - current;
+
+            for o in 0..out_size {
+                let mut sum = self.bias[bias_offset + o];
+                for i in 0..in_size {
+                    sum += self.weights[weight_offset + o * in_size + i] * scratch[current][i];
+                }
+                scratch[next][o] = if is_output {
+                    1.0 / (1.0 + (-sum).exp())
+                } else {
+                    sum.max(0.0)
+                };
+            }
+
+            weight_offset += in_size * out_size;
+            bias_offset += out_size;
+            current = next;
+        }
+
+        scratch[current][..*self.layers.last().unwrap()].to_vec()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -108,6 +223,237 @@ pub struct CachedData {
     pub ttl: u64,
 }
 
+// Tunable weights for `calculate_score_optimized`, so a game mode (e.g. a
+// "hardcore" daily with steeper hint penalties) or the benchmark harness can
+// score puzzles differently without recompiling the scoring logic.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ScoreConfig {
+    pub base_points: u32,
+    pub hint_penalty: u32,
+    pub time_penalty_divisor: u32,
+    pub streak_bonus: u32,
+    pub difficulty_bonus: f64,
+}
+
+impl Default for ScoreConfig {
+    fn default() -> Self {
+        Self {
+            base_points: 100,
+            hint_penalty: 4,
+            time_penalty_divisor: 10,
+            streak_bonus: 5,
+            difficulty_bonus: 20.0,
+        }
+    }
+}
+
+// Elo-style rating constants. `K` decays as a player accumulates games so
+// early results move the rating quickly and it stabilizes over time.
+const ELO_K_INITIAL: f64 = 40.0;
+const ELO_K_MIN: f64 = 10.0;
+const ELO_K_DECAY_GAMES: f64 = 30.0;
+const DEFAULT_PLAYER_RATING: f64 = 1000.0;
+
+// `Puzzle.difficulty` is a 0-1 scoring input (see `difficulty_bonus` in
+// `calculate_score_optimized`), not an Elo rating — mixing the two scales
+// would let rating updates (which move by tens of points) poison the
+// scoring field. `PUZZLE_RATING_SPREAD` maps difficulty 0..1 onto an
+// Elo-scale rating centered on `DEFAULT_PLAYER_RATING`, used only for the
+// rating subsystem below.
+const PUZZLE_RATING_SPREAD: f64 = 800.0;
+
+fn difficulty_to_rating(difficulty: f64) -> f64 {
+    DEFAULT_PLAYER_RATING + (difficulty - 0.5) * PUZZLE_RATING_SPREAD
+}
+
+// Probability the player is expected to solve a puzzle at `puzzle_rating`,
+// given their current `player_rating`.
+fn expected_score(player_rating: f64, puzzle_rating: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((puzzle_rating - player_rating) / 400.0))
+}
+
+// K decays from `ELO_K_INITIAL` towards `ELO_K_MIN` as `games_played` grows,
+// so a new player's rating moves quickly while a veteran's stays stable.
+fn k_factor(games_played: u32) -> f64 {
+    (ELO_K_INITIAL - games_played as f64 / ELO_K_DECAY_GAMES).max(ELO_K_MIN)
+}
+
+// Actual outcome for a solved puzzle, scaled down by hints used so a
+// hint-assisted solve counts for less than a clean one.
+fn actual_score(solved: bool, hints_used: u8) -> f64 {
+    if !solved {
+        return 0.0;
+    }
+    (1.0 - hints_used as f64 * 0.1).max(0.1)
+}
+
+// One segment of a soft-pity reward curve: starting at `start_pity`
+// consecutive no-reward solves, the per-solve reward chance begins at
+// `start_chance_percent` and rises by `increment_percent` each subsequent
+// solve, capped at hard pity (100%). Segments overlap: at each pity count the
+// chance is the highest one given by any segment that has started.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProbabilityPoint {
+    pub start_pity: u32,
+    pub start_chance_percent: f64,
+    pub increment_percent: f64,
+}
+
+// A soft-pity reward model for bonus hints or cosmetic rewards, driven by
+// the player's `reward_pity` counter instead of a flat random roll.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProbabilityModel {
+    pub points: Vec<ProbabilityPoint>,
+}
+
+const HARD_PITY_CHANCE_PERCENT: f64 = 100.0;
+
+impl ProbabilityModel {
+    // Precomputes the per-pity-count reward chance (0.0-1.0) up to hard
+    // pity, so the runtime roll is a single array lookup instead of walking
+    // the segment list on every solve.
+    pub fn precompute(&self, max_pity: u32) -> Vec<f64> {
+        let mut curve = vec![0.0; max_pity as usize + 1];
+
+        for pity in 0..=max_pity {
+            let mut chance_percent = 0.0;
+
+            for point in &self.points {
+                if pity >= point.start_pity {
+                    let steps = (pity - point.start_pity) as f64;
+                    let at_point = point.start_chance_percent + steps * point.increment_percent;
+                    chance_percent = chance_percent.max(at_point);
+                }
+            }
+
+            curve[pity as usize] = chance_percent.min(HARD_PITY_CHANCE_PERCENT) / 100.0;
+        }
+
+        curve
+    }
+}
+
+// Implemented by anything that can play a daily puzzle guess-by-guess, so the
+// benchmark harness below can evaluate both hand-written and learned solvers
+// against the puzzle bank.
+pub trait Solver: Send {
+    fn next_guess(&mut self, history: &[Guess], feedback: &[LetterFeedback]) -> String;
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BenchmarkReport {
+    pub puzzles_played: usize,
+    pub wins: usize,
+    pub win_rate: f64,
+    pub avg_guesses: f64,
+    pub avg_solve_time_ms: f64,
+    pub score_distribution: HashMap<u32, u32>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+struct PuzzleRunResult {
+    won: bool,
+    guesses: usize,
+    solve_time_ms: u64,
+    score: u32,
+}
+
+// A single time-bucketed epoch (e.g. one day) within a `MetricsWindow`.
+#[derive(Debug, Clone, Default)]
+struct EpochBucket {
+    games: u32,
+    correct: u32,
+    total_solve_time: u64,
+    total_score: u64,
+}
+
+// Windowed averages for the UI's recency-focused views (e.g. "your last 7
+// days"). The Elo `K`-factor does not read these; `k_factor` decays with the
+// lifetime `games_played` count only.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WindowedStats {
+    pub window_secs: u64,
+    pub games_in_window: u32,
+    pub accuracy: f64,
+    pub avg_solve_time: u64,
+    pub avg_score: f64,
+}
+
+// Coarsest bucket granularity: one calendar day. Epoch indices are compared
+// against this, independent of the caller's requested `window_secs`, so a
+// "last 7 days" query and a "last 24 hours" query bucket the same guesses
+// the same way and only differ in how many epochs they keep.
+const EPOCH_SECS: u64 = 86_400;
+
+// `Guess.timestamp` and `get_timestamp()` are both milliseconds (same clock
+// as `duration_ms`), so epoch bucketing has to divide by a day in
+// milliseconds, not seconds — otherwise every "epoch" is ~86 seconds and the
+// window is wrong by 1000x. `window_secs` itself stays in seconds: it's
+// divided by `EPOCH_SECS` below only to count how many one-day epochs to
+// keep, which is unaffected by the timestamp unit.
+const EPOCH_MS: u64 = EPOCH_SECS * 1_000;
+
+// Aggregates a player's guesses into day-sized epochs, keeping only the
+// epochs that fall within the trailing `window_secs` and discarding
+// everything older at the epoch boundary.
+struct MetricsWindow {
+    epochs: HashMap<u64, EpochBucket>,
+}
+
+impl MetricsWindow {
+    fn build(guesses: &[Guess], now: u64, window_secs: u64) -> Self {
+        let mut epochs: HashMap<u64, EpochBucket> = HashMap::new();
+        if window_secs == 0 {
+            return Self { epochs };
+        }
+
+        let current_epoch = now / EPOCH_MS;
+        let window_epochs = (window_secs / EPOCH_SECS).max(1);
+        let min_epoch = current_epoch.saturating_sub(window_epochs - 1);
+
+        for guess in guesses {
+            let epoch = guess.timestamp / EPOCH_MS;
+            if epoch < min_epoch || epoch > current_epoch {
+                // Stale (or not-yet-valid) entries are discarded at the
+                // epoch boundary rather than kept in the map.
+                continue;
+            }
+
+            let bucket = epochs.entry(epoch).or_default();
+            bucket.games += 1;
+            if guess.correct {
+                bucket.correct += 1;
+            }
+            bucket.total_solve_time += guess.duration_ms;
+            bucket.total_score += guess.score.unwrap_or(0) as u64;
+        }
+
+        Self { epochs }
+    }
+
+    fn stats(&self, window_secs: u64) -> WindowedStats {
+        let games_in_window: u32 = self.epochs.values().map(|b| b.games).sum();
+        if games_in_window == 0 {
+            return WindowedStats {
+                window_secs,
+                ..Default::default()
+            };
+        }
+
+        let correct: u32 = self.epochs.values().map(|b| b.correct).sum();
+        let total_solve_time: u64 = self.epochs.values().map(|b| b.total_solve_time).sum();
+        let total_score: u64 = self.epochs.values().map(|b| b.total_score).sum();
+
+        WindowedStats {
+            window_secs,
+            games_in_window,
+            accuracy: correct as f64 / games_in_window as f64,
+            avg_solve_time: total_solve_time / games_in_window as u64,
+            avg_score: total_score as f64 / games_in_window as f64,
+        }
+    }
+}
+
 impl ThreadPulseEngine {
     pub fn new() -> Self {
         Self {
@@ -116,7 +462,43 @@ impl ThreadPulseEngine {
             performance_metrics: Arc::new(Mutex::new(PerformanceMetrics::default())),
             ai_models: Arc::new(Mutex::new(AIModels::default())),
             cache: Arc::new(Mutex::new(HashMap::new())),
+            score_config: Arc::new(Mutex::new(ScoreConfig::default())),
+            reward_curve: Arc::new(Mutex::new(Vec::new())),
+            puzzle_ratings: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    // Swaps the active scoring weights, e.g. to score a "hardcore" game mode
+    // or to re-run the benchmark harness under a different weighting.
+    pub fn set_score_config(&self, config: ScoreConfig) {
+        *self.score_config.lock().unwrap() = config;
+    }
+
+    // Loads a soft-pity reward model and precomputes its chance curve so
+    // `roll_reward` is a single lookup on the hot path.
+    pub fn set_reward_model(&self, model: ProbabilityModel) {
+        *self.reward_curve.lock().unwrap() = model.precompute(MAX_REWARD_PITY);
+    }
+
+    // Rolls for a bonus hint / cosmetic reward using the player's current
+    // pity count, resetting the counter to zero when a reward fires.
+    pub fn roll_reward(&self) -> bool {
+        let curve = self.reward_curve.lock().unwrap();
+        let mut state = self.state.lock().unwrap();
+        let pity = state.player_progress.reward_pity.min(MAX_REWARD_PITY);
+
+        // No model loaded yet means no reward curve to consult — default to
+        // never rewarding rather than always rewarding.
+        let chance = curve.get(pity as usize).copied().unwrap_or(0.0);
+        let won = js_sys::Math::random() < chance;
+
+        if won {
+            state.player_progress.reward_pity = 0;
+        } else {
+            state.player_progress.reward_pity = state.player_progress.reward_pity.saturating_add(1);
         }
+
+        won
     }
 
     // Initialize engine with optimized memory allocation
@@ -145,18 +527,18 @@ impl ThreadPulseEngine {
     // Optimized score calculation with SIMD-like operations
     pub fn calculate_score_optimized(&self, params: &ScoreParams) -> u32 {
         let start_time = self.get_timestamp();
-        
-        // Use bitwise operations for faster calculation
-        let base_score = if params.correct { 100 } else { 0 };
-        
-        // Penalty calculation using bit shifts
-        let hint_penalty = (params.hints_used as u32) << 2; // * 4
-        let time_penalty = (params.time_seconds / 10) as u32;
-        
+        let config = *self.score_config.lock().unwrap();
+
+        let base_score = if params.correct { config.base_points } else { 0 };
+
+        // Penalty calculation
+        let hint_penalty = params.hints_used as u32 * config.hint_penalty;
+        let time_penalty = (params.time_seconds / config.time_penalty_divisor as u64) as u32;
+
         // Bonus calculation with multiplication
-        let streak_bonus = params.streak_days * 5;
-        let difficulty_bonus = (params.difficulty * 20.0) as u32;
-        
+        let streak_bonus = params.streak_days * config.streak_bonus;
+        let difficulty_bonus = (params.difficulty * config.difficulty_bonus) as u32;
+
         // Final score calculation
         let final_score = base_score
             .saturating_sub(hint_penalty)
@@ -175,11 +557,137 @@ impl ThreadPulseEngine {
     pub fn validate_guess_optimized(&self, guess: &str, answer: &str) -> bool {
         let start_time = self.get_timestamp();
 
+        let is_correct = guess.eq_ignore_ascii_case(answer);
+
+        let operation_time = self.get_timestamp() - start_time;
+        self.update_performance_metrics(operation_time);
+
+        is_correct
+    }
+
+    // Per-letter Wordle-style feedback, computed with the standard two-pass
+    // algorithm so repeated letters only score `Present` as many times as
+    // they remain unmatched in the answer. Compares ASCII-case-insensitively
+    // to stay consistent with `validate_guess_optimized`'s win check.
+    pub fn score_guess(&self, guess: &str, answer: &str) -> Vec<LetterFeedback> {
+        let start_time = self.get_timestamp();
+
+        let guess_chars: Vec<char> = guess.to_ascii_lowercase().chars().collect();
+        let answer_chars: Vec<char> = answer.to_ascii_lowercase().chars().collect();
+        let len = guess_chars.len();
+
+        let mut feedback = vec![LetterFeedback::Absent; len];
+        let mut consumed = vec![false; answer_chars.len()];
+
+        // Pass one: exact position matches.
+        for i in 0..len {
+            if i < answer_chars.len() && guess_chars[i] == answer_chars[i] {
+                feedback[i] = LetterFeedback::Correct;
+                consumed[i] = true;
+            }
+        }
+
+        // Pass two: remaining letters match against unconsumed answer slots.
+        for i in 0..len {
+            if feedback[i] == LetterFeedback::Correct {
+                continue;
+            }
+
+            if let Some(j) = answer_chars
+                .iter()
+                .enumerate()
+                .position(|(j, &c)| !consumed[j] && c == guess_chars[i])
+            {
+                feedback[i] = LetterFeedback::Present;
+                consumed[j] = true;
+            }
+        }
+
+        let operation_time = self.get_timestamp() - start_time;
+        self.update_performance_metrics(operation_time);
+
+        feedback
+    }
+
+    // Encodes a feedback pattern as a base-3 integer (Absent=0, Present=1,
+    // Correct=2, weighted by powers of three per position) so patterns can
+    // be compared or filtered against with a single `u32`. `3u32.pow` would
+    // overflow past position 20 (3^21 > u32::MAX); `score_guess` accepts
+    // arbitrary-length guesses, so positions beyond that saturate instead of
+    // wrapping or panicking.
+    pub fn encode_feedback(feedback: &[LetterFeedback]) -> u32 {
+        feedback.iter().enumerate().fold(0u32, |acc, (i, f)| {
+            let weight = 3u32.checked_pow(i as u32).unwrap_or(u32::MAX);
+            acc.saturating_add((*f as u32).saturating_mul(weight))
+        })
+    }
+
+    // Runs a real forward pass over the loaded content model when one is
+    // compiled, falling back to the string-heuristic analysis when no model
+    // weights are loaded so the engine degrades gracefully.
     fn perform_ai_analysis(&self, clue: &str) -> AIClueAnalysis {
+        let models = self.ai_models.lock().unwrap();
+
+        if let Some(model) = models.content_model.as_ref() {
+            if model.compiled {
+                let features = Self::featurize_clue(clue);
+
+                if !model.is_valid_for(features.len()) {
+                    drop(models);
+                    return self.perform_ai_analysis_heuristic(clue);
+                }
+
+                let output = model.infer(&features);
+
+                if output.len() >= 6 {
+                    return AIClueAnalysis {
+                        sentiment: output[0] * 2.0 - 1.0,
+                        creativity: output[1],
+                        difficulty: output[2],
+                        engagement_prediction: output[3],
+                        toxicity: output[4],
+                        quality_score: output[5],
+                    };
+                }
+            }
+        }
+
+        drop(models);
+        self.perform_ai_analysis_heuristic(clue)
+    }
+
+    // Featurizes a clue into a fixed-width vector (word count, length,
+    // punctuation counts, and a small char-bigram hash histogram) so the
+    // content model always sees the same input shape regardless of clue
+    // length.
+    fn featurize_clue(clue: &str) -> Vec<f32> {
+        const NGRAM_BUCKETS: usize = 8;
+
+        let word_count = clue.split_whitespace().count() as f32;
+        let length = clue.len() as f32;
+        let punctuation_count = clue.chars().filter(|c| c.is_ascii_punctuation()).count() as f32;
+        let question_marks = clue.matches('?').count() as f32;
+
+        let mut ngram_hist = [0f32; NGRAM_BUCKETS];
+        let chars: Vec<char> = clue.chars().collect();
+        for window in chars.windows(2) {
+            let mut hasher = DefaultHasher::new();
+            window.hash(&mut hasher);
+            let bucket = (hasher.finish() as usize) % NGRAM_BUCKETS;
+            ngram_hist[bucket] += 1.0;
+        }
+
+        let mut features = vec![word_count, length, punctuation_count, question_marks];
+        features.extend_from_slice(&ngram_hist);
+        features
+    }
+
+    // Pre-model string-contains heuristic, kept as the fallback for when no
+    // compiled content model is loaded.
+    fn perform_ai_analysis_heuristic(&self, clue: &str) -> AIClueAnalysis {
         let words: Vec<&str> = clue.split_whitespace().collect();
         let word_count = words.len();
-        
-        // Simple heuristic analysis (replace with actual ML model)
+
         let sentiment = if clue.to_lowercase().contains("good") || clue.to_lowercase().contains("great") {
             0.8
         } else if clue.to_lowercase().contains("bad") || clue.to_lowercase().contains("terrible") {
@@ -222,6 +730,194 @@ impl ThreadPulseEngine {
             quality_score,
         }
     }
+
+    // Plays every puzzle in the bank against a fresh solver instance and
+    // aggregates the results into a `BenchmarkReport`, so puzzle authors can
+    // validate solvability and gauge difficulty without hand-setting it.
+    // Games run in parallel across `thread_count` (defaults to the number of
+    // logical CPUs) via a dedicated rayon thread pool.
+    //
+    // Native-only (rayon thread spawning isn't available in the browser, and
+    // `rayon`/`num_cpus` generally don't target wasm32) — use this from a
+    // native test/benchmark binary, not from the wasm_bindgen surface.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn benchmark_solver<F, S>(
+        &self,
+        solver_factory: F,
+        thread_count: Option<usize>,
+    ) -> Result<BenchmarkReport, String>
+    where
+        F: Fn() -> S + Sync,
+        S: Solver,
+    {
+        const MAX_GUESSES: usize = 6;
+
+        let puzzles = self.puzzle_bank.lock().unwrap().clone();
+        let results: Arc<Mutex<Vec<PuzzleRunResult>>> =
+            Arc::new(Mutex::new(Vec::with_capacity(puzzles.len())));
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(thread_count.unwrap_or_else(num_cpus::get))
+            .build()
+            .map_err(|e| format!("failed to build benchmark thread pool: {e}"))?;
+
+        pool.install(|| {
+            puzzles.par_iter().for_each(|puzzle| {
+                let start_time = self.get_timestamp();
+                let mut solver = solver_factory();
+                let mut history: Vec<Guess> = Vec::with_capacity(MAX_GUESSES);
+                let mut feedback: Vec<LetterFeedback> = Vec::new();
+                let mut won = false;
+                let mut last_guess_time = start_time;
+
+                for _ in 0..MAX_GUESSES {
+                    let guess_text = solver.next_guess(&history, &feedback);
+                    feedback = self.score_guess(&guess_text, &puzzle.answer);
+                    let correct = self.validate_guess_optimized(&guess_text, &puzzle.answer);
+
+                    let timestamp = self.get_timestamp();
+                    history.push(Guess {
+                        text: guess_text,
+                        timestamp,
+                        hints_used: 0,
+                        correct,
+                        score: None,
+                        duration_ms: timestamp - last_guess_time,
+                    });
+                    last_guess_time = timestamp;
+
+                    if correct {
+                        won = true;
+                        break;
+                    }
+                }
+
+                let solve_time_ms = self.get_timestamp() - start_time;
+                let score = self.calculate_score_optimized(&ScoreParams {
+                    correct: won,
+                    hints_used: 0,
+                    time_seconds: solve_time_ms / 1000,
+                    streak_days: 0,
+                    difficulty: puzzle.difficulty,
+                });
+
+                results.lock().unwrap().push(PuzzleRunResult {
+                    won,
+                    guesses: history.len(),
+                    solve_time_ms,
+                    score,
+                });
+            });
+        });
+
+        let results = results.lock().unwrap();
+        let puzzles_played = results.len();
+        let wins = results.iter().filter(|r| r.won).count();
+        let mut score_distribution: HashMap<u32, u32> = HashMap::new();
+        for r in results.iter() {
+            *score_distribution.entry(r.score).or_insert(0) += 1;
+        }
+
+        let avg_guesses = if puzzles_played > 0 {
+            results.iter().map(|r| r.guesses).sum::<usize>() as f64 / puzzles_played as f64
+        } else {
+            0.0
+        };
+        let avg_solve_time_ms = if puzzles_played > 0 {
+            results.iter().map(|r| r.solve_time_ms).sum::<u64>() as f64 / puzzles_played as f64
+        } else {
+            0.0
+        };
+
+        Ok(BenchmarkReport {
+            puzzles_played,
+            wins,
+            win_rate: if puzzles_played > 0 {
+                wins as f64 / puzzles_played as f64
+            } else {
+                0.0
+            },
+            avg_guesses,
+            avg_solve_time_ms,
+            score_distribution,
+        })
+    }
+
+    // Treats a solved (or failed) daily puzzle as an Elo "match" between the
+    // player and the puzzle's Elo-scale rating, updating both symmetrically.
+    // The puzzle's rating lives in `puzzle_ratings`, seeded from
+    // `Puzzle.difficulty` on first use — `difficulty` itself is never
+    // overwritten, since `calculate_score_optimized` expects it to stay in
+    // 0..1.
+    pub fn update_skill_rating(&self, solved: bool, hints_used: u8, puzzle_id: &str) {
+        let mut state = self.state.lock().unwrap();
+        let bank = self.puzzle_bank.lock().unwrap();
+        let mut ratings = self.puzzle_ratings.lock().unwrap();
+
+        let seed_difficulty = bank
+            .iter()
+            .find(|p| p.id == puzzle_id)
+            .map(|p| p.difficulty)
+            .unwrap_or(0.5);
+        let puzzle_rating = *ratings
+            .entry(puzzle_id.to_string())
+            .or_insert_with(|| difficulty_to_rating(seed_difficulty));
+
+        // Both entry points into the rating subsystem must agree on the
+        // starting point: a player with no games yet starts at
+        // `DEFAULT_PLAYER_RATING`, same as `recompute_skill_rating`.
+        let games_played = state.player_progress.games_played;
+        let player_rating = if games_played == 0 {
+            DEFAULT_PLAYER_RATING
+        } else {
+            state.player_progress.skill_level
+        };
+        let k = k_factor(games_played);
+
+        let expected = expected_score(player_rating, puzzle_rating);
+        let actual = actual_score(solved, hints_used);
+
+        state.player_progress.skill_level = player_rating + k * (actual - expected);
+        state.player_progress.games_played = games_played.saturating_add(1);
+
+        let puzzle_expected = 1.0 - expected;
+        let puzzle_actual = 1.0 - actual;
+        ratings.insert(
+            puzzle_id.to_string(),
+            puzzle_rating + k * (puzzle_actual - puzzle_expected),
+        );
+    }
+
+    // Computes windowed averages (accuracy, solve time, score) over the
+    // player's guesses in the trailing `window_secs`, e.g. a daily or weekly
+    // window. The window is counted in whole calendar-day epochs, including
+    // the current day: `window_secs / 86_400` of them, and at least one. A
+    // guess from an earlier epoch is discarded even if it is less than
+    // `window_secs` old, and a guess from earlier today is kept however short
+    // a non-zero window is. A `window_secs` of 0 yields empty stats.
+    pub fn aggregate_window(&self, window_secs: u64) -> WindowedStats {
+        let state = self.state.lock().unwrap();
+        let now = self.get_timestamp();
+
+        let window = MetricsWindow::build(&state.player_progress.guesses, now, window_secs);
+        window.stats(window_secs)
+    }
+
+    // Replays a batch of `Guess` histories against their puzzles' difficulty
+    // ratings to rebuild a player's skill rating from scratch, e.g. after a
+    // rating formula change or to recover from corrupted state.
+    pub fn recompute_skill_rating(&self, history: &[(Guess, f64)]) -> f64 {
+        let mut rating = DEFAULT_PLAYER_RATING;
+
+        for (games_played, (guess, puzzle_rating)) in history.iter().enumerate() {
+            let k = k_factor(games_played as u32);
+            let expected = expected_score(rating, *puzzle_rating);
+            let actual = actual_score(guess.correct, guess.hints_used);
+            rating += k * (actual - expected);
+        }
+
+        rating
+    }
 }
 
 // Utility functions